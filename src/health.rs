@@ -0,0 +1,149 @@
+// Copyright © 2025 Andrea Corbellini and contributors
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Continuous health checks for the raw samples behind [`try_get()`](crate::try_get).
+//!
+//! These are lightweight online tests, modeled on the repetition count test and adaptive
+//! proportion test used by other jitter-style entropy sources, meant to catch a CPU clock that has
+//! stopped advancing or that is stuck oscillating between a handful of values (see
+//! [Limitations](crate#limitations)).
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::hint::spin_loop;
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::Ordering;
+
+/// Error returned by [`try_get()`](crate::try_get) when a continuous health check detects that
+/// the entropy source looks degraded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EntropyError {
+    /// The repetition count test failed: the raw CPU counter returned the same value too many
+    /// times in a row, which usually means the clock has stopped advancing.
+    RepetitionCountTestFailed,
+    /// The adaptive proportion test failed: too many samples within a fixed-size window equaled
+    /// the window's reference sample, which usually means the clock is advancing too slowly or is
+    /// stuck oscillating between a small set of values.
+    AdaptiveProportionTestFailed,
+}
+
+impl fmt::Display for EntropyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::RepetitionCountTestFailed => "repetition count test failed",
+            Self::AdaptiveProportionTestFailed => "adaptive proportion test failed",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl core::error::Error for EntropyError {}
+
+// Repetition Count Test: fail if the same raw sample is observed this many times in a row.
+const RCT_CUTOFF: u16 = 10;
+
+// Adaptive Proportion Test: number of samples observed per window...
+const APT_WINDOW: u16 = 512;
+// ...and the maximum number of samples within a window that may equal the window's reference
+// sample before the test is considered failed. This is deliberately generous, since it only needs
+// to catch a clock that is grossly stuck, not to bound the statistical quality of the output. The
+// reference sample itself is excluded from the comparison (it trivially equals itself), so each
+// window really only tests `APT_WINDOW - 1` samples against this cutoff, matching how the
+// Repetition Count/Adaptive Proportion tests are specified in NIST SP 800-90B.
+const APT_CUTOFF: u16 = 410;
+
+// A tiny spinlock guarding test state that must be updated as a single unit. `check()` is called
+// on every [`try_get()`](crate::try_get), so contention is expected to be rare and brief; a
+// spinlock avoids pulling in a full mutex for a `no_std` crate.
+struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: `with()` only ever hands out the inner `&mut T` to one caller at a time, guarded by
+// `locked`.
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+impl<T> SpinLock<T> {
+    const fn new(value: T) -> Self {
+        Self { locked: AtomicBool::new(false), value: UnsafeCell::new(value) }
+    }
+
+    fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            spin_loop();
+        }
+        // SAFETY: we just acquired the lock, so no other caller can be holding `&mut` to `value`.
+        let result = f(unsafe { &mut *self.value.get() });
+        self.locked.store(false, Ordering::Release);
+        result
+    }
+}
+
+struct RctState {
+    last: u64,
+    run: u16,
+}
+
+struct AptState {
+    reference: u64,
+    count: u16,
+    matches: u16,
+}
+
+static RCT: SpinLock<RctState> = SpinLock::new(RctState { last: 0, run: 0 });
+static APT: SpinLock<AptState> = SpinLock::new(AptState { reference: 0, count: 0, matches: 0 });
+
+#[inline]
+pub(crate) fn check(raw: u64) -> Result<(), EntropyError> {
+    repetition_count_test(raw)?;
+    adaptive_proportion_test(raw)?;
+    Ok(())
+}
+
+fn repetition_count_test(raw: u64) -> Result<(), EntropyError> {
+    RCT.with(|state| {
+        if raw == state.last {
+            state.run += 1;
+            if state.run >= RCT_CUTOFF {
+                return Err(EntropyError::RepetitionCountTestFailed);
+            }
+        } else {
+            state.last = raw;
+            state.run = 1;
+        }
+        Ok(())
+    })
+}
+
+fn adaptive_proportion_test(raw: u64) -> Result<(), EntropyError> {
+    APT.with(|state| {
+        state.count += 1;
+        if state.count == 1 {
+            // First sample of a new window: it becomes the reference.
+            state.reference = raw;
+            state.matches = 0;
+            return Ok(());
+        }
+
+        if raw == state.reference {
+            state.matches += 1;
+        }
+
+        if state.count >= APT_WINDOW {
+            // Window complete: start a fresh one on the next sample.
+            state.count = 0;
+        }
+
+        if state.matches > APT_CUTOFF {
+            return Err(EntropyError::AdaptiveProportionTestFailed);
+        }
+
+        Ok(())
+    })
+}
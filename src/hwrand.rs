@@ -0,0 +1,181 @@
+// Copyright © 2025 Andrea Corbellini and contributors
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! On-die hardware DRNG (`RDSEED`/`RDRAND`) for x86 and x86\_64.
+//!
+//! This module is internal: its only purpose is to let [`crate::get()`] fold in genuine hardware
+//! entropy on CPUs that support it, in addition to the CPU counter that this crate always relies
+//! on. Support is detected once (via `CPUID`) and cached, so that [`get()`](crate::get) stays
+//! cheap on every call after the first.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::__cpuid_count;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::__cpuid_count;
+use core::sync::atomic::AtomicU8;
+use core::sync::atomic::Ordering;
+
+// `SUPPORT` has not been computed yet.
+const UNKNOWN: u8 = 0;
+// The CPU supports `RDSEED` (which implies `RDRAND` is not used, since `RDSEED` is the
+// higher-quality source).
+const RDSEED: u8 = 1;
+// The CPU supports `RDRAND`, but not `RDSEED`.
+const RDRAND: u8 = 2;
+// The CPU supports neither instruction.
+const NONE: u8 = 3;
+
+static SUPPORT: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+// `RDRAND`/`RDSEED` may transiently fail (e.g. if the DRNG hasn't replenished its internal pool
+// yet). Intel's "Digital Random Number Generator (DRNG) Software Implementation Guide"
+// recommends retrying a handful of times before giving up.
+const MAX_RETRIES: u32 = 10;
+
+fn detect() -> u8 {
+    // `__cpuid_count` is a stable core intrinsic for the `CPUID` instruction; it takes care of
+    // saving/restoring `EBX`, which LLVM reserves and inline `asm!` cannot clobber directly.
+    // https://www.intel.com/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-vol-2a-manual.pdf
+
+    // CPUID leaf 1, ECX bit 30: RDRAND support.
+    let leaf1 = __cpuid_count(1, 0);
+    let has_rdrand = leaf1.ecx & (1 << 30) != 0;
+
+    // CPUID leaf 0, EAX: highest basic leaf supported. Querying leaf 7 on a CPU that doesn't
+    // support it returns the highest supported leaf's data instead (SDM Vol. 2A, 3.2 "CPUID"),
+    // so `leaf7.ebx` would be garbage and could spuriously claim RDSEED support on hardware old
+    // enough to have neither instruction — exactly this crate's target audience.
+    let max_leaf = __cpuid_count(0, 0).eax;
+    let has_rdseed = max_leaf >= 7 && __cpuid_count(7, 0).ebx & (1 << 18) != 0;
+
+    if has_rdseed {
+        RDSEED
+    } else if has_rdrand {
+        RDRAND
+    } else {
+        NONE
+    }
+}
+
+#[inline(always)]
+fn support() -> u8 {
+    let cached = SUPPORT.load(Ordering::Relaxed);
+    if cached != UNKNOWN {
+        return cached;
+    }
+    let detected = detect();
+    SUPPORT.store(detected, Ordering::Relaxed);
+    detected
+}
+
+// Runs `step` (a single `RDRAND`/`RDSEED` attempt) up to `MAX_RETRIES` times, stopping at the
+// first success, since both instructions may transiently fail.
+#[inline(always)]
+fn retry<T>(mut step: impl FnMut() -> Option<T>) -> Option<T> {
+    for _ in 0..MAX_RETRIES {
+        if let Some(value) = step() {
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[cfg(target_arch = "x86_64")]
+mod instr {
+    use core::arch::asm;
+
+    #[inline(always)]
+    pub(super) unsafe fn rdrand_step() -> Option<u64> {
+        let val: u64;
+        let ok: u8;
+        asm!(
+            "rdrand {val}",
+            "setc {ok}",
+            val = out(reg) val,
+            ok = out(reg_byte) ok,
+            options(nomem, nostack),
+        );
+        (ok != 0).then_some(val)
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn rdseed_step() -> Option<u64> {
+        let val: u64;
+        let ok: u8;
+        asm!(
+            "rdseed {val}",
+            "setc {ok}",
+            val = out(reg) val,
+            ok = out(reg_byte) ok,
+            options(nomem, nostack),
+        );
+        (ok != 0).then_some(val)
+    }
+
+    pub(super) fn rdrand() -> Option<u64> {
+        super::retry(|| unsafe { rdrand_step() })
+    }
+
+    pub(super) fn rdseed() -> Option<u64> {
+        super::retry(|| unsafe { rdseed_step() })
+    }
+}
+
+#[cfg(target_arch = "x86")]
+mod instr {
+    use core::arch::asm;
+
+    #[inline(always)]
+    pub(super) unsafe fn rdrand_step() -> Option<u32> {
+        let val: u32;
+        let ok: u8;
+        asm!(
+            "rdrand {val:e}",
+            "setc {ok}",
+            val = out(reg) val,
+            ok = out(reg_byte) ok,
+            options(nomem, nostack),
+        );
+        (ok != 0).then_some(val)
+    }
+
+    #[inline(always)]
+    pub(super) unsafe fn rdseed_step() -> Option<u32> {
+        let val: u32;
+        let ok: u8;
+        asm!(
+            "rdseed {val:e}",
+            "setc {ok}",
+            val = out(reg) val,
+            ok = out(reg_byte) ok,
+            options(nomem, nostack),
+        );
+        (ok != 0).then_some(val)
+    }
+
+    // `RDRAND`/`RDSEED` operate on a GPR, so on 32-bit x86 each call only yields 32 bits. Two
+    // independently-retried halves are combined into a `u64`, mirroring how `cpu_counter()`
+    // assembles `EDX:EAX` into a 64-bit value on this target.
+    pub(super) fn rdrand() -> Option<u64> {
+        let hi = super::retry(|| unsafe { rdrand_step() })?;
+        let lo = super::retry(|| unsafe { rdrand_step() })?;
+        Some(((hi as u64) << 32) | lo as u64)
+    }
+
+    pub(super) fn rdseed() -> Option<u64> {
+        let hi = super::retry(|| unsafe { rdseed_step() })?;
+        let lo = super::retry(|| unsafe { rdseed_step() })?;
+        Some(((hi as u64) << 32) | lo as u64)
+    }
+}
+
+/// Returns a 64-bit value from the on-die DRNG, using `RDSEED` when the CPU supports it and
+/// `RDRAND` otherwise, or `None` if the CPU supports neither instruction or the instruction in use
+/// is exhausted after retrying. A failing `RDSEED` does not fall back to `RDRAND`.
+pub(crate) fn get() -> Option<u64> {
+    match support() {
+        RDSEED => instr::rdseed(),
+        RDRAND => instr::rdrand(),
+        _ => None,
+    }
+}
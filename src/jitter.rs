@@ -0,0 +1,74 @@
+// Copyright © 2025 Andrea Corbellini and contributors
+// SPDX-License-Identifier: BSD-2-Clause
+
+//! Timing-jitter entropy collector.
+//!
+//! [`collect()`] extracts entropy from micro-architectural timing jitter (caches, branch
+//! prediction, interrupts, ...) rather than from a single [`cpu_counter()`](crate) reading. This
+//! makes it more robust than [`get()`](crate::get) on machines where the CPU clock is
+//! low-frequency or otherwise weak, at the cost of being slower to call.
+//!
+//! # Examples
+//!
+//! ```
+//! let mut buf = [0u8; 30];
+//! poorentropy::jitter::collect(&mut buf);
+//! assert_ne!(buf, [0u8; 30]);
+//! ```
+
+use crate::cpu_counter;
+use crate::split_mix_64;
+
+// Number of timing measurements folded into the rolling mix state for every output byte. Each
+// measurement only contributes a single raw bit, so this many are collected to give the health of
+// each byte a comfortable margin.
+const MEASUREMENTS_PER_BYTE: u32 = 64;
+
+// Runs a short workload whose timing depends on `seed`, walking a small stack buffer with
+// data-dependent indices, and returns the number of CPU clock ticks it took. The absolute value
+// doesn't matter: `raw_bit()` folds all of its bits into one, and what varies from call to call
+// is its low bits, which carry physical jitter from caches, branch prediction, and interrupts.
+#[inline(always)]
+fn timed_step(seed: u64) -> u64 {
+    let mut buf = [0u8; 32];
+    let mut idx = (seed as usize) & (buf.len() - 1);
+    let start = cpu_counter();
+    for _ in 0..buf.len() {
+        buf[idx] = buf[idx].wrapping_add(1);
+        idx = (idx.wrapping_add(buf[idx] as usize)) & (buf.len() - 1);
+    }
+    let end = cpu_counter();
+    core::hint::black_box(buf);
+    end.wrapping_sub(start)
+}
+
+// Folds a timing delta down to a single bit by XOR-ing all of its bits together.
+#[inline(always)]
+fn raw_bit(delta: u64) -> u64 {
+    (delta.count_ones() & 1) as u64
+}
+
+/// Fills `out` with entropy extracted from CPU timing jitter.
+///
+/// This is `no_std`-friendly: it only needs a fixed-size stack buffer, and does not depend on any
+/// of the other entropy sources in this crate. It can be used standalone, or mixed into another
+/// source (for example XOR-ing its output with [`fill()`](crate::fill)'s) for extra robustness.
+///
+/// # Examples
+///
+/// ```
+/// let mut buf = [0u8; 8];
+/// poorentropy::jitter::collect(&mut buf);
+/// assert_ne!(buf, [0u8; 8]);
+/// ```
+pub fn collect(out: &mut [u8]) {
+    let mut state = cpu_counter();
+    let mut delta = cpu_counter();
+    for byte in out.iter_mut() {
+        for _ in 0..MEASUREMENTS_PER_BYTE {
+            delta = timed_step(delta);
+            state = split_mix_64(state ^ raw_bit(delta));
+        }
+        *byte = state as u8;
+    }
+}
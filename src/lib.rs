@@ -30,6 +30,9 @@
 //!
 //! [`fill()`] and [`bytes()`] can be used to obtain the entropy as bytes.
 //!
+//! The [`jitter`] module offers an alternative source based on CPU timing jitter, for use on its
+//! own or mixed into the sources above for extra robustness.
+//!
 //! Generally speaking, entropy sources should not be used directly, but should rather be used as a
 //! seed for a pseudo-random number generator. Here is an example using the [`rand`
 //! crate](https://crates.io/crates/rand):
@@ -48,6 +51,18 @@
 //! # let _ = r;
 //! ```
 //!
+//! When the optional `rand_core` feature is enabled, [`seed()`] does the same thing in a single
+//! call:
+//!
+//! ```ignore
+//! use rand::RngCore;
+//! use rand::rngs::SmallRng;
+//!
+//! let mut rng: SmallRng = poorentropy::seed();
+//! let r = rng.next_u32();
+//! # let _ = r;
+//! ```
+//!
 //! # How It Works
 //!
 //! The crate works by reading the CPU "clock" or "cycle counter", and mixing it to produce a
@@ -70,6 +85,12 @@
 //! [SplitMix64](https://en.wikipedia.org/wiki/Xorshift#Initialization) generator to make it appear
 //! random.
 //!
+//! On x86 and x86\_64, if the CPU exposes an on-die digital random number generator, its output is
+//! also mixed in: `RDSEED` is used when available, otherwise `RDRAND`, and the counter-only mix
+//! above is used if neither instruction is supported or the one in use fails after a few retries
+//! (a failing `RDSEED` does not fall back to `RDRAND`). Support is detected once (via `CPUID`) and
+//! cached, so this adds no overhead on CPUs without a DRNG.
+//!
 //! # Limitations
 //!
 //! * Because the crate relies on the CPU clock, the values that it produces may be easy to
@@ -97,6 +118,32 @@
 
 pub mod iter;
 
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "loongarch64",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+pub mod jitter;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod hwrand;
+
+mod health;
+
+pub use health::EntropyError;
+
+#[cfg(feature = "rand_core")]
+mod rng;
+
+#[cfg(feature = "rand_core")]
+pub use rng::Rng;
+#[cfg(feature = "rand_core")]
+pub use rng::ReseedingRng;
+#[cfg(feature = "rand_core")]
+pub use rng::seed;
+
 use core::arch::asm;
 use core::cmp::min;
 use core::sync::atomic::AtomicU64;
@@ -245,17 +292,62 @@ fn split_mix_64(state: u64) -> u64 {
     target_arch = "x86_64"
 ))]
 pub fn get() -> u64 {
-    // Get the clock/tick counter from the CPU (`cpu_counter()`), and then add an atomic monotonic
-    // counter to it (`internal_counter()`). The atomic monotonic counter serves two purposes:
-    //
-    // 1. it helps ensuring that if two threads call `get()` at the same time, they will see
-    //    different values;
-    // 2. work around the limitation on some architectures (ARM, AArch64) where the clock updates
-    //    at a low frequency, therefore subsequent calls to `cpu_counter()` are *very likely* to
-    //    return the same value.
-    let cnt = cpu_counter().wrapping_add(internal_counter());
-    // Use a pseudo-random number generator to make the output look random.
-    split_mix_64(cnt)
+    mix(cpu_counter())
+}
+
+// Get the clock/tick counter from the CPU (`cpu_counter()`), and then add an atomic monotonic
+// counter to it (`internal_counter()`). The atomic monotonic counter serves two purposes:
+//
+// 1. it helps ensuring that if two threads call `get()` at the same time, they will see
+//    different values;
+// 2. work around the limitation on some architectures (ARM, AArch64) where the clock updates
+//    at a low frequency, therefore subsequent calls to `cpu_counter()` are *very likely* to
+//    return the same value.
+//
+// Use a pseudo-random number generator to make the output look random. On x86/x86\_64, if the CPU
+// has an on-die DRNG (`RDSEED`/`RDRAND`), its output is folded in too, so that the result is
+// never worse than the plain counter-based mix but benefits from genuine hardware entropy where
+// available.
+#[inline]
+fn mix(raw: u64) -> u64 {
+    let cnt = raw.wrapping_add(internal_counter());
+    let mixed = split_mix_64(cnt);
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    let mixed = match hwrand::get() {
+        Some(hw) => split_mix_64(mixed ^ hw),
+        None => mixed,
+    };
+    mixed
+}
+
+/// Returns a pseudo-random value as a [`u64`], like [`get()`], but runs continuous health checks
+/// on the raw CPU counter first and fails if they detect that the entropy source looks degraded
+/// (for example because the clock has stopped advancing; see
+/// [Limitations](crate#limitations)).
+///
+/// Most callers should prefer the infallible [`get()`]. `try_get()` is meant for contexts such as
+/// firmware, bootloaders, or kernels, where silently returning stuck entropy would be worse than
+/// an error that lets the caller fall back to another source.
+///
+/// # Examples
+///
+/// ```
+/// match poorentropy::try_get() {
+///     Ok(e) => { let _ = e; }
+///     Err(e) => eprintln!("entropy source looks degraded: {e}"),
+/// }
+/// ```
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "loongarch64",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+pub fn try_get() -> Result<u64, EntropyError> {
+    let raw = cpu_counter();
+    health::check(raw)?;
+    Ok(mix(raw))
 }
 
 /// Fills a byte buffer with pseudo-random bytes.
@@ -371,6 +463,23 @@ mod tests {
         }
     }
 
+    mod try_get {
+        #[test]
+        fn ok_under_normal_conditions() {
+            for _ in 0..8000 {
+                crate::try_get().unwrap();
+            }
+        }
+
+        #[test]
+        fn monte_carlo() {
+            let iter = core::iter::from_fn(|| {
+                Some((crate::try_get().unwrap() as f64) / (u64::MAX as f64))
+            });
+            super::monte_carlo(iter);
+        }
+    }
+
     mod fill {
         #[test]
         fn monte_carlo() {
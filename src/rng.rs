@@ -1,7 +1,10 @@
 // Copyright © 2025 Andrea Corbellini and contributors
 // SPDX-License-Identifier: BSD-2-Clause
 
+use core::fmt;
+use core::mem::size_of;
 use rand_core::RngCore;
+use rand_core::SeedableRng;
 
 /// `poorentropy` implementation for use with the [`rand` crate]
 ///
@@ -34,6 +37,118 @@ impl RngCore for Rng {
     }
 }
 
+/// An [`RngCore`] adapter that wraps an inner pseudo-random number generator `R` and periodically
+/// reseeds it from [`poorentropy::fill()`](crate::fill).
+///
+/// This struct is available only when the optional `rand_core` feature is enabled.
+///
+/// The docs for this crate recommend seeding a PRNG once from [`fill()`](crate::fill). That is
+/// fine for short-lived programs, but a single seed goes stale over the lifetime of a
+/// long-running `no_std` program. `ReseedingRng` reconstructs the inner generator from fresh
+/// entropy every time a configurable number of bytes have been generated from it, so callers get
+/// the inner PRNG's speed between reseeds while still continuously folding in fresh CPU-counter
+/// entropy.
+///
+/// # Examples
+///
+/// ```
+/// use rand::RngCore;
+/// use rand::rngs::SmallRng;
+/// use poorentropy::ReseedingRng;
+///
+/// // Reseed every 1 MiB of generated output.
+/// let mut rng = ReseedingRng::<SmallRng>::new(1024 * 1024);
+/// let a = rng.next_u32();
+/// let b = rng.next_u32();
+/// assert_ne!(a, b);
+/// ```
+pub struct ReseedingRng<R> {
+    inner: R,
+    threshold: u64,
+    generated: u64,
+}
+
+impl<R: SeedableRng> ReseedingRng<R> {
+    /// Creates a new `ReseedingRng`, seeding the inner generator from
+    /// [`poorentropy::fill()`](crate::fill) and reseeding it the same way again every time
+    /// `threshold` bytes of output have been generated from it.
+    #[must_use]
+    pub fn new(threshold: u64) -> Self {
+        Self {
+            inner: Self::reseed(),
+            threshold,
+            generated: 0,
+        }
+    }
+
+    fn reseed() -> R {
+        let mut seed = R::Seed::default();
+        crate::fill(seed.as_mut());
+        R::from_seed(seed)
+    }
+
+    fn reseed_if_due(&mut self) {
+        if self.generated >= self.threshold {
+            self.inner = Self::reseed();
+            self.generated = 0;
+        }
+    }
+}
+
+impl<R: SeedableRng> fmt::Debug for ReseedingRng<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReseedingRng")
+            .field("threshold", &self.threshold)
+            .field("generated", &self.generated)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Builds a [`SeedableRng`] seed from [`fill()`](crate::fill), and constructs `R` from it.
+///
+/// This is available only when the optional `rand_core` feature is enabled.
+///
+/// [`fill()`](crate::fill) mixes the CPU counter, the internal atomic counter, and, on
+/// x86/x86\_64, the on-die `RDSEED`/`RDRAND` DRNG where available (see
+/// [How It Works](crate#how-it-works)); the [`jitter`](crate::jitter) collector is not used. This
+/// is just a thin convenience wrapper that turns the seed-then-construct pattern from the crate
+/// docs into a single call.
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::SmallRng;
+///
+/// let rng: SmallRng = poorentropy::seed();
+/// # let _ = rng;
+/// ```
+#[must_use]
+pub fn seed<R: SeedableRng>() -> R {
+    let mut seed = R::Seed::default();
+    crate::fill(seed.as_mut());
+    R::from_seed(seed)
+}
+
+impl<R: SeedableRng + RngCore> RngCore for ReseedingRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.reseed_if_due();
+        self.generated += size_of::<u32>() as u64;
+        self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.reseed_if_due();
+        self.generated += size_of::<u64>() as u64;
+        self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.reseed_if_due();
+        self.generated += dst.len() as u64;
+        self.inner.fill_bytes(dst)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Rng;
@@ -64,4 +179,69 @@ mod tests {
         rng.fill_bytes(&mut b);
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn seed() {
+        use rand::rngs::SmallRng;
+
+        let mut a: SmallRng = super::seed();
+        let mut b: SmallRng = super::seed();
+        assert_ne!(a.next_u64(), b.next_u64());
+    }
+
+    mod reseeding_rng {
+        use crate::ReseedingRng;
+        use rand_core::RngCore;
+        use rand_core::SeedableRng;
+
+        // A minimal counter-based PRNG, used only so these tests don't need an external crate.
+        #[derive(Default)]
+        struct Counter(u64);
+
+        impl RngCore for Counter {
+            fn next_u32(&mut self) -> u32 {
+                self.next_u64() as u32
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                self.0 = self.0.wrapping_add(1);
+                self.0
+            }
+
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                for chunk in dst.chunks_mut(8) {
+                    let bytes = self.next_u64().to_le_bytes();
+                    chunk.copy_from_slice(&bytes[..chunk.len()]);
+                }
+            }
+        }
+
+        impl SeedableRng for Counter {
+            type Seed = [u8; 8];
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                Self(u64::from_le_bytes(seed))
+            }
+        }
+
+        #[test]
+        fn next_u32() {
+            let mut rng = ReseedingRng::<Counter>::new(u64::MAX);
+            let a = rng.next_u32();
+            let b = rng.next_u32();
+            assert_ne!(a, b);
+        }
+
+        #[test]
+        fn reseeds_after_threshold() {
+            let mut rng = ReseedingRng::<Counter>::new(8);
+            let before_reseed = rng.inner.0;
+            rng.next_u64();
+            assert_ne!(rng.generated, 0, "byte counter should advance before the threshold");
+            // Crossing the threshold above should have triggered a reseed, so the inner
+            // generator's state should no longer be a simple increment of its previous value.
+            rng.next_u64();
+            assert_ne!(rng.inner.0, before_reseed.wrapping_add(2));
+        }
+    }
 }